@@ -1,8 +1,158 @@
 use elm_rs::{Elm, ElmDecode, ElmEncode};
 use serde::Deserialize;
 use serde::Serialize;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+// StlBytes is sent as a JSON number array, so keep payloads bounded
+const MAX_STL_FILE_SIZE: u64 = 64 * 1024 * 1024;
 
 #[derive(Serialize, Deserialize, Debug, Elm, ElmEncode, ElmDecode, Clone)]
 pub struct StlBytes {
     pub bytes: Vec<u8>,
 }
+
+#[derive(Serialize, Debug, Elm, ElmDecode, Clone)]
+pub struct StlLoadError {
+    pub path: String,
+    pub reason: String,
+}
+
+impl StlLoadError {
+    fn from_io(path: &Path, err: &io::Error) -> Self {
+        let reason = match err.kind() {
+            io::ErrorKind::NotFound => "file not found".to_string(),
+            io::ErrorKind::PermissionDenied => "permission denied".to_string(),
+            _ => err.to_string(),
+        };
+        StlLoadError {
+            path: path.display().to_string(),
+            reason,
+        }
+    }
+
+    // size is None when the file outgrew its metadata while being read
+    fn too_large(path: &Path, limit: u64, size: Option<u64>) -> Self {
+        let limit = format_size(limit);
+        let reason = match size {
+            Some(size) => {
+                let size = format_size(size);
+                format!("file is too large ({size}, limit is {limit})")
+            }
+            None => format!("file is too large (limit is {limit})"),
+        };
+        StlLoadError {
+            path: path.display().to_string(),
+            reason,
+        }
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    const MIB: u64 = 1024 * 1024;
+    if bytes >= MIB {
+        format!("{:.1} MiB", bytes as f64 / MIB as f64)
+    } else {
+        format!("{bytes} bytes")
+    }
+}
+
+pub fn read_stl_bytes(path: &Path) -> Result<Vec<u8>, StlLoadError> {
+    read_bytes_with_limit(path, MAX_STL_FILE_SIZE)
+}
+
+// metadata is only a fast path: the bounded read also catches files that grow
+// after the check and special files that report a length of 0
+fn read_bytes_with_limit(path: &Path, limit: u64) -> Result<Vec<u8>, StlLoadError> {
+    let file = File::open(path).map_err(|e| StlLoadError::from_io(path, &e))?;
+    let size = file
+        .metadata()
+        .map_err(|e| StlLoadError::from_io(path, &e))?
+        .len();
+    if size > limit {
+        return Err(StlLoadError::too_large(path, limit, Some(size)));
+    }
+
+    let mut buf: Vec<u8> = Vec::new();
+    file.take(limit + 1)
+        .read_to_end(&mut buf)
+        .map_err(|e| StlLoadError::from_io(path, &e))?;
+    if buf.len() as u64 > limit {
+        return Err(StlLoadError::too_large(path, limit, None));
+    }
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str, bytes: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), name));
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn reports_missing_file() {
+        let path = std::env::temp_dir().join(format!("{}-missing.stl", std::process::id()));
+        let err = read_stl_bytes(&path).unwrap_err();
+        assert_eq!(err.path, path.display().to_string());
+        assert_eq!(err.reason, "file not found");
+    }
+
+    #[test]
+    fn reports_permission_denied() {
+        let err = StlLoadError::from_io(
+            Path::new("model.stl"),
+            &io::Error::from(io::ErrorKind::PermissionDenied),
+        );
+        assert_eq!(err.path, "model.stl");
+        assert_eq!(err.reason, "permission denied");
+    }
+
+    #[test]
+    fn falls_back_to_io_error_message() {
+        let err = StlLoadError::from_io(Path::new("model.stl"), &io::Error::other("disk on fire"));
+        assert_eq!(err.reason, "disk on fire");
+    }
+
+    #[test]
+    fn reads_file_within_limit() {
+        let path = temp_file("within-limit.stl", &[1, 2, 3, 4]);
+        let result = read_bytes_with_limit(&path, 4);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rejects_file_over_limit() {
+        let path = temp_file("over-limit.stl", &[1, 2, 3, 4, 5]);
+        let result = read_bytes_with_limit(&path, 4);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            result.unwrap_err().reason,
+            "file is too large (5 bytes, limit is 4 bytes)"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_unbounded_special_file() {
+        // /dev/zero reports a length of 0 but never ends
+        let result = read_bytes_with_limit(Path::new("/dev/zero"), 4);
+        assert_eq!(
+            result.unwrap_err().reason,
+            "file is too large (limit is 4 bytes)"
+        );
+    }
+
+    #[test]
+    fn formats_sizes_in_mib() {
+        assert_eq!(format_size(MAX_STL_FILE_SIZE), "64.0 MiB");
+        assert_eq!(format_size(MAX_STL_FILE_SIZE + 512 * 1024), "64.5 MiB");
+        assert_eq!(format_size(1023), "1023 bytes");
+    }
+}
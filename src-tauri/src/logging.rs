@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tracing_appender::rolling::{InitError, RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload, Registry};
+
+const LOG_FILE_NAME: &str = "tauri-app.log";
+const MAX_LOG_FILES: usize = 7;
+const RECENT_LOGS_CAPACITY: usize = 500;
+
+pub struct LogState {
+    level: reload::Handle<LevelFilter, Registry>,
+    recent: RecentLogs,
+}
+
+impl LogState {
+    pub fn set_level(&self, level: &str) -> Result<(), String> {
+        let level = parse_level(level)?;
+        self.level.reload(level).map_err(|e| e.to_string())
+    }
+
+    pub fn recent_logs(&self) -> Vec<String> {
+        self.recent.lines()
+    }
+}
+
+// keeps the last RECENT_LOGS_CAPACITY formatted lines for the log panel
+#[derive(Clone, Default)]
+struct RecentLogs(Arc<Mutex<VecDeque<String>>>);
+
+impl RecentLogs {
+    fn push(&self, line: String) {
+        let mut lines = self.0.lock().unwrap();
+        if lines.len() == RECENT_LOGS_CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    fn lines(&self) -> Vec<String> {
+        self.0.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+impl io::Write for RecentLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // the fmt layer writes each event with a single write_all
+        self.push(String::from_utf8_lossy(buf).trim_end().to_string());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, String> {
+    level.parse::<LevelFilter>().map_err(|e| e.to_string())
+}
+
+fn build_log_file(log_dir: &Path) -> Result<RollingFileAppender, InitError> {
+    RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(LOG_FILE_NAME)
+        .max_log_files(MAX_LOG_FILES)
+        .build(log_dir)
+}
+
+// a missing or unwritable log dir only disables the file layer; stdout and
+// the recent logs buffer keep working
+pub fn init(log_dir: Option<&Path>) -> LogState {
+    let mut log_file_error = None;
+    let log_file = match log_dir {
+        Some(dir) => match build_log_file(dir) {
+            Ok(appender) => Some(appender),
+            Err(e) => {
+                log_file_error = Some(format!("cannot write to {}: {e}", dir.display()));
+                None
+            }
+        },
+        None => {
+            log_file_error = Some("cannot resolve the app log directory".to_string());
+            None
+        }
+    };
+    let (level, level_handle) = reload::Layer::new(LevelFilter::INFO);
+    let recent = RecentLogs::default();
+    let recent_writer = recent.clone();
+
+    tracing_subscriber::registry()
+        .with(level)
+        .with(fmt::layer())
+        .with(log_file.map(|writer| fmt::layer().with_ansi(false).with_writer(writer)))
+        .with(
+            fmt::layer()
+                .with_ansi(false)
+                .with_writer(move || recent_writer.clone()),
+        )
+        .init();
+
+    if let Some(reason) = log_file_error {
+        tracing::warn!("file logging disabled: {reason}");
+    }
+
+    LogState {
+        level: level_handle,
+        recent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn recent_logs_drops_oldest_line_when_full() {
+        let mut recent = RecentLogs::default();
+        for i in 0..=RECENT_LOGS_CAPACITY {
+            // mirrors the fmt layer: one write_all per event, newline included
+            recent.write_all(format!("line {i}\n").as_bytes()).unwrap();
+        }
+
+        let lines = recent.lines();
+        assert_eq!(lines.len(), RECENT_LOGS_CAPACITY);
+        assert_eq!(lines.first().unwrap(), "line 1");
+        assert_eq!(
+            lines.last().unwrap(),
+            &format!("line {RECENT_LOGS_CAPACITY}")
+        );
+    }
+
+    #[test]
+    fn parse_level_accepts_known_levels() {
+        assert_eq!(parse_level("warn"), Ok(LevelFilter::WARN));
+        assert_eq!(parse_level("off"), Ok(LevelFilter::OFF));
+    }
+
+    #[test]
+    fn parse_level_rejects_unknown_level() {
+        assert!(parse_level("verbose").is_err());
+    }
+}
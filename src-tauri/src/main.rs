@@ -1,10 +1,12 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 mod data;
+mod logging;
 
-use data::stl::StlBytes;
-use std::io::Read;
+use data::stl::{read_stl_bytes, StlBytes, StlLoadError};
+use logging::LogState;
 use tauri::api::dialog::FileDialogBuilder;
+use tauri::Manager;
 
 #[tauri::command]
 fn read_stl_file(window: tauri::Window) -> () {
@@ -15,15 +17,18 @@ fn read_stl_file(window: tauri::Window) -> () {
             // the file path is `None` if the user closed the dialog
             match file_path {
                 Some(path) => {
-
-                    let mut input = std::fs::File::open(path).unwrap();
-
-                    let mut buf: Vec<u8> = Vec::new();
-                    input.read_to_end(&mut buf).unwrap();
-                    test_app_handle(window, buf);
+                    let _span =
+                        tracing::info_span!("read_stl_file", path = %path.display()).entered();
+                    match read_stl_bytes(&path) {
+                        Ok(buf) => test_app_handle(window, buf),
+                        Err(e) => {
+                            tracing::error!("failed to read stl file: {}", e.reason);
+                            send_stl_load_error(&window, e);
+                        }
+                    }
                 }
                 None => {
-                    println!("User closed the dialog without selecting a file");
+                    tracing::info!("User closed the dialog without selecting a file");
                 }
             }
         })
@@ -33,18 +38,34 @@ fn read_stl_file(window: tauri::Window) -> () {
 #[tauri::command]
 fn test_app_handle(window: tauri::Window, data: Vec<u8>) {
     match window.emit("tauri_msg", StlBytes { bytes: data }) {
-        Ok(_) => println!("event sent successfully"),
-        Err(e) => println!("failed to send event: {}", e),
+        Ok(_) => tracing::debug!("event sent successfully"),
+        Err(e) => tracing::error!("failed to send event: {}", e),
+    }
+}
+
+fn send_stl_load_error(window: &tauri::Window, err: StlLoadError) {
+    if let Err(e) = window.emit("stl_load_error", err) {
+        tracing::error!("failed to send event: {}", e);
     }
 }
 
+#[tauri::command]
+fn set_log_level(state: tauri::State<LogState>, level: String) -> Result<(), String> {
+    state.set_level(&level)
+}
+
+#[tauri::command]
+fn request_recent_logs(state: tauri::State<LogState>) -> Vec<String> {
+    state.recent_logs()
+}
+
 fn main() {
     // the target would typically be a file
     let mut target = vec![];
     // elm_rs provides a macro for conveniently creating an Elm module with everything needed
     elm_rs::export!("Bindings", &mut target, {
         encoders: [StlBytes],
-        decoders: [StlBytes],
+        decoders: [StlBytes, StlLoadError],
     })
     .unwrap();
     let output = String::from_utf8(target).unwrap();
@@ -52,7 +73,17 @@ fn main() {
     std::fs::write("../src/elm/Bindings.elm", output).unwrap();
 
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![read_stl_file, test_app_handle])
+        .setup(|app| {
+            let log_dir = app.path_resolver().app_log_dir();
+            app.manage(logging::init(log_dir.as_deref()));
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            read_stl_file,
+            test_app_handle,
+            set_log_level,
+            request_recent_logs
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -46,3 +46,16 @@ stlBytesDecoder =
         |> Json.Decode.andThen (\x -> Json.Decode.map x (Json.Decode.field "bytes" (Json.Decode.list (Json.Decode.int))))
 
 
+type alias StlLoadError =
+    { path : String
+    , reason : String
+    }
+
+
+stlLoadErrorDecoder : Json.Decode.Decoder StlLoadError
+stlLoadErrorDecoder =
+    Json.Decode.succeed StlLoadError
+        |> Json.Decode.andThen (\x -> Json.Decode.map x (Json.Decode.field "path" (Json.Decode.string)))
+        |> Json.Decode.andThen (\x -> Json.Decode.map x (Json.Decode.field "reason" (Json.Decode.string)))
+
+
@@ -9,8 +9,9 @@ import Color
 import Decoder exposing (Stl, Vec, decodeStl, encodeToBytes)
 import Direction3d
 import Html exposing (..)
-import Html.Attributes exposing (value)
+import Html.Attributes exposing (selected, value)
 import Html.Events exposing (..)
+import Html.Keyed
 import Json.Decode
 import Length exposing (Meters)
 import Pixels exposing (int)
@@ -30,6 +31,24 @@ port readStlFileResult : (String -> msg) -> Sub msg
 port tauriMsg : (Json.Decode.Value -> msg) -> Sub msg
 
 
+port stlLoadError : (Json.Decode.Value -> msg) -> Sub msg
+
+
+port requestRecentLogs : () -> Cmd msg
+
+
+port recentLogs : (List String -> msg) -> Sub msg
+
+
+port setLogLevel : String -> Cmd msg
+
+
+port logLevelApplied : (String -> msg) -> Sub msg
+
+
+port logLevelRejected : (String -> msg) -> Sub msg
+
+
 
 -- MAIN
 
@@ -50,13 +69,25 @@ main =
 
 type alias Model =
     { stl : Maybe Stl
+    , loadError : Maybe Bindings.StlLoadError
     , viewPoint : Vec
+    , logs : List String
+    , logLevel : String
+    , logLevelError : Maybe String
+    , logLevelRevision : Int
     }
 
 
 init : () -> ( Model, Cmd Msg )
 init _ =
-    ( { stl = Nothing, viewPoint = ( 50, 20, 30 ) }
+    ( { stl = Nothing
+      , loadError = Nothing
+      , viewPoint = ( 50, 20, 30 )
+      , logs = []
+      , logLevel = "info"
+      , logLevelError = Nothing
+      , logLevelRevision = 0
+      }
     , readStlFile ()
     )
 
@@ -68,13 +99,19 @@ init _ =
 type Msg
     = ReadStlFile
     | TauriMsg Json.Decode.Value
+    | StlLoadError Json.Decode.Value
+    | RequestRecentLogs
+    | RecentLogs (List String)
+    | SetLogLevel String
+    | LogLevelApplied String
+    | LogLevelRejected String
 
 
 update : Msg -> Model -> ( Model, Cmd Msg )
 update msg model =
     case msg of
         ReadStlFile ->
-            ( model
+            ( { model | loadError = Nothing }
             , readStlFile ()
             )
 
@@ -86,10 +123,46 @@ update msg model =
                         |> Result.toMaybe
                         |> Maybe.map encodeToBytes
                         |> Maybe.andThen decodeStl
+                , loadError = Nothing
+              }
+            , Cmd.none
+            )
+
+        StlLoadError value ->
+            ( { model
+                | loadError =
+                    Json.Decode.decodeValue Bindings.stlLoadErrorDecoder value
+                        |> Result.toMaybe
               }
             , Cmd.none
             )
 
+        RequestRecentLogs ->
+            ( model
+            , requestRecentLogs ()
+            )
+
+        RecentLogs lines ->
+            ( { model | logs = lines }
+            , Cmd.none
+            )
+
+        SetLogLevel level ->
+            ( model
+            , setLogLevel level
+            )
+
+        LogLevelApplied level ->
+            ( { model | logLevel = level, logLevelError = Nothing }
+            , Cmd.none
+            )
+
+        LogLevelRejected err ->
+            -- bump the revision so the select is rebuilt showing the level still in effect
+            ( { model | logLevelError = Just err, logLevelRevision = model.logLevelRevision + 1 }
+            , Cmd.none
+            )
+
 
 
 -- SUBSCRIPTIONS
@@ -99,6 +172,10 @@ subscriptions : Model -> Sub Msg
 subscriptions _ =
     Sub.batch
         [ tauriMsg TauriMsg
+        , stlLoadError StlLoadError
+        , recentLogs RecentLogs
+        , logLevelApplied LogLevelApplied
+        , logLevelRejected LogLevelRejected
         ]
 
 
@@ -123,6 +200,9 @@ view model =
     div []
         [ h1 [] [ text "Read stl file" ]
         , button [ onClick ReadStlFile ] [ text "Read stl file" ]
+        , model.loadError
+            |> Maybe.map (\err -> div [] [ text <| "failed to read " ++ err.path ++ ": " ++ err.reason ])
+            |> Maybe.withDefault (text "")
         , div [] [ text <| "len: " ++ (String.fromInt <| Maybe.withDefault 0 <| Maybe.map (\stl -> stl.numTriangles) <| model.stl) ]
         , model.stl
             |> Maybe.map .triangles
@@ -139,9 +219,34 @@ view model =
                 )
             |> Maybe.withDefault (text "")
         , div [] [ text <| "decoded: " ++ Debug.toString model.stl ]
+        , logPanel model
+        ]
+
+
+logPanel : Model -> Html Msg
+logPanel model =
+    div []
+        [ h2 [] [ text "Logs" ]
+        , Html.Keyed.node "span"
+            []
+            [ ( String.fromInt model.logLevelRevision
+              , select [ onInput SetLogLevel ]
+                    (List.map (\level -> option [ value level, selected (level == model.logLevel) ] [ text level ]) logLevels)
+              )
+            ]
+        , model.logLevelError
+            |> Maybe.map (\err -> div [] [ text <| "failed to set log level: " ++ err ])
+            |> Maybe.withDefault (text "")
+        , button [ onClick RequestRecentLogs ] [ text "Refresh logs" ]
+        , pre [] [ text <| String.join "\n" model.logs ]
         ]
 
 
+logLevels : List String
+logLevels =
+    [ "trace", "debug", "info", "warn", "error", "off" ]
+
+
 
 ------------
 -- SCENE
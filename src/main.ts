@@ -32,6 +32,32 @@ await listen("tauri_msg", (e) => {
   app.ports.tauriMsg.send(e.payload);
 });
 
+await listen("stl_load_error", (e) => {
+  console.error(e);
+  app.ports.stlLoadError.send(e.payload);
+});
+
 app.ports.readStlFile.subscribe(async function () {
   await invoke("read_stl_file");
 });
+
+async function sendRecentLogs() {
+  try {
+    app.ports.recentLogs.send(await invoke("request_recent_logs"));
+  } catch (e) {
+    console.error(e);
+  }
+}
+
+app.ports.requestRecentLogs.subscribe(sendRecentLogs);
+
+app.ports.setLogLevel.subscribe(async function (level: string) {
+  try {
+    await invoke("set_log_level", { level });
+    app.ports.logLevelApplied.send(level);
+  } catch (e) {
+    console.error(e);
+    app.ports.logLevelRejected.send(String(e));
+  }
+  await sendRecentLogs();
+});